@@ -0,0 +1,59 @@
+use std::error::Error;
+
+use rand::Rng;
+
+use crate::stats::{self, SimulationSummary};
+
+/// Draws a single path of `years` rates from `history` using the stationary
+/// bootstrap: repeatedly pick a random start index into the history and copy
+/// a contiguous run of length drawn from a geometric distribution with mean
+/// `mean_block_len`, wrapping around the series end circularly.
+fn bootstrap_path(history: &[f64], years: usize, mean_block_len: f64, rng: &mut impl Rng) -> Vec<f64> {
+    let n = history.len();
+    let p = 1.0 / mean_block_len;
+    let mut path = Vec::with_capacity(years);
+
+    while path.len() < years {
+        let start = rng.gen_range(0..n);
+        let block_len = sample_geometric(rng, p);
+        for offset in 0..block_len {
+            if path.len() >= years {
+                break;
+            }
+            path.push(history[(start + offset) % n]);
+        }
+    }
+
+    path
+}
+
+/// Samples a geometric random variable (number of trials until the first
+/// success, minimum 1) via inverse-CDF sampling.
+fn sample_geometric(rng: &mut impl Rng, p: f64) -> usize {
+    let u: f64 = rng.gen();
+    (((1.0 - u).ln() / (1.0 - p).ln()).floor() as usize) + 1
+}
+
+/// Stationary block-bootstrap counterpart to `run_multiple_simulations`:
+/// instead of drawing Gaussian shocks around an OU process, each path is
+/// stitched together from blocks of the observed `history`. Summarized the
+/// same way (mean, arbitrary percentiles, and a per-year boxplot) so both
+/// modes can be compared on the same chart.
+pub fn run_bootstrap_simulations(
+    num_simulations: usize,
+    years: usize,
+    history: &[f64],
+    mean_block_len: f64,
+    percentiles: &[f64],
+    rng: &mut impl Rng,
+) -> Result<SimulationSummary, Box<dyn Error>> {
+    if history.is_empty() {
+        return Err("cannot bootstrap from an empty history".into());
+    }
+
+    let all_simulations: Vec<Vec<f64>> = (0..num_simulations)
+        .map(|_| bootstrap_path(history, years, mean_block_len, rng))
+        .collect();
+
+    Ok(stats::summarize(all_simulations, years, percentiles))
+}
@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fs;
+
+/// Parameters of the Ornstein-Uhlenbeck inflation process, estimated from
+/// a historical series rather than hard-coded.
+pub struct CalibratedParams {
+    pub start_rate: f64,
+    pub volatility: f64,
+    pub mean_reversion: f64,
+    pub long_term_mean: f64,
+}
+
+/// Reads a `date,rate` CSV of historical annual inflation rates and
+/// calibrates the OU process parameters by OLS.
+///
+/// The discretized OU process `r_{t+1} = r_t + a*(mu - r_t) + eps` is an
+/// AR(1) model `r_{t+1} = alpha + beta*r_t + eps` with `beta = 1 - a` and
+/// `alpha = a*mu`. Fitting `alpha, beta` by ordinary least squares over
+/// consecutive pairs lets us recover `a`, `mu`, and the shock volatility.
+pub fn calibrate_from_csv(path: &str) -> Result<CalibratedParams, Box<dyn Error>> {
+    let rates = read_rates_csv(path)?;
+
+    if rates.len() < 3 {
+        return Err("need at least three historical rates to calibrate (two AR(1) pairs)".into());
+    }
+
+    let xs: Vec<f64> = rates[..rates.len() - 1].to_vec();
+    let ys: Vec<f64> = rates[1..].to_vec();
+    let (alpha, beta) = ols_fit(&xs, &ys)?;
+
+    let residuals: Vec<f64> = xs
+        .iter()
+        .zip(ys.iter())
+        .map(|(&x, &y)| y - (alpha + beta * x))
+        .collect();
+    let volatility = stddev(&residuals);
+
+    let mean_reversion = 1.0 - beta;
+    let long_term_mean = alpha / (1.0 - beta);
+    let start_rate = *rates.last().unwrap();
+
+    Ok(CalibratedParams {
+        start_rate,
+        volatility,
+        mean_reversion,
+        long_term_mean,
+    })
+}
+
+/// Reads the `rate` column of a `date,rate` CSV of historical annual
+/// inflation rates, skipping any header row.
+pub fn read_rates_csv(path: &str) -> Result<Vec<f64>, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    parse_rates(&contents)
+}
+
+fn parse_rates(contents: &str) -> Result<Vec<f64>, Box<dyn Error>> {
+    let mut rates = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let rate_field = line
+            .split(',')
+            .nth(1)
+            .ok_or_else(|| format!("malformed CSV line: {}", line))?;
+        match rate_field.trim().parse::<f64>() {
+            Ok(rate) => rates.push(rate),
+            Err(_) => continue, // skip header row
+        }
+    }
+    Ok(rates)
+}
+
+fn ols_fit(xs: &[f64], ys: &[f64]) -> Result<(f64, f64), Box<dyn Error>> {
+    let n = xs.len() as f64;
+    let x_mean = xs.iter().sum::<f64>() / n;
+    let y_mean = ys.iter().sum::<f64>() / n;
+
+    let mut cov = 0.0;
+    let mut var = 0.0;
+    for (&x, &y) in xs.iter().zip(ys.iter()) {
+        cov += (x - x_mean) * (y - y_mean);
+        var += (x - x_mean) * (x - x_mean);
+    }
+
+    if var == 0.0 {
+        return Err("historical rates have no variation; cannot fit a mean-reverting model".into());
+    }
+
+    let beta = cov / var;
+    if (1.0 - beta).abs() < 1e-9 {
+        return Err(
+            "historical rates show no mean reversion (beta ~= 1.0); cannot fit a mean-reverting model"
+                .into(),
+        );
+    }
+
+    let alpha = y_mean - beta * x_mean;
+    Ok((alpha, beta))
+}
+
+fn stddev(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|&v| (v - mean).powi(2)).sum::<f64>() / n;
+    variance.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ols_fit_rejects_flat_history() {
+        let xs = vec![0.02, 0.02, 0.02];
+        let ys = vec![0.02, 0.02, 0.02];
+        assert!(ols_fit(&xs, &ys).is_err());
+    }
+
+    #[test]
+    fn ols_fit_rejects_perfectly_linear_history() {
+        let xs = vec![0.0, 0.25, 0.5];
+        let ys = vec![0.25, 0.5, 0.75];
+        assert!(ols_fit(&xs, &ys).is_err());
+    }
+
+    #[test]
+    fn ols_fit_recovers_finite_params_for_mean_reverting_history() {
+        let xs = vec![0.04, 0.03, 0.02, 0.025];
+        let ys = vec![0.03, 0.02, 0.025, 0.022];
+        let (alpha, beta) = ols_fit(&xs, &ys).unwrap();
+        assert!(alpha.is_finite());
+        assert!(beta.is_finite());
+        assert!((1.0 - beta).abs() > 1e-9);
+    }
+}
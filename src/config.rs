@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use crate::get_user_input;
+
+/// All simulation and financial parameters the tool needs, gathered up
+/// front so a run can be driven non-interactively via CLI flags or a
+/// config file instead of the original hard-coded constants.
+pub struct Config {
+    pub seed: u64,
+    pub goal: f64,
+    pub current_age: u32,
+    pub target_age: u32,
+    pub monthly_salary: f64,
+    pub start_inflation_rate: f64,
+    pub inflation_volatility: f64,
+    pub mean_reversion_speed: f64,
+    pub long_term_inflation_mean: f64,
+    pub num_simulations: usize,
+    pub annual_return: f64,
+    pub mean_block_len: f64,
+    pub history_path: String,
+    pub use_bootstrap: String,
+    pub use_pid: String,
+    pub pid_p: f64,
+    pub pid_i: f64,
+    pub pid_d: f64,
+    pub pid_target: f64,
+    pub pid_cap: f64,
+    pub run_fire: String,
+    pub fire_monthly_expenses: f64,
+    pub fire_income_growth_rate: f64,
+    pub fire_retirement_monthly_expenses: f64,
+    pub fire_withdrawal_rate: f64,
+    pub run_decumulation: String,
+    pub decumulation_ending_portfolio: f64,
+    pub decumulation_withdrawal_rate: f64,
+    pub decumulation_life_expectancy_years: usize,
+    pub decumulation_annual_fee: f64,
+    pub decumulation_tax_rate: f64,
+    pub decumulation_real_return: f64,
+    pub decumulation_target_failure_rate: f64,
+    pub output_format: String,
+}
+
+impl Config {
+    /// Loads the config from `--flag value` CLI arguments and/or a
+    /// `key=value` file passed via `--config <path>`; CLI flags win over the
+    /// config file. Any field supplied by neither falls back to an
+    /// interactive prompt, so the tool still runs with no arguments by
+    /// prompting for every value.
+    pub fn load() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let mut values: HashMap<String, String> = HashMap::new();
+
+        if let Some(path) = flag_value(&args, "--config") {
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                for line in contents.lines() {
+                    let line = line.trim();
+                    if line.is_empty() || line.starts_with('#') {
+                        continue;
+                    }
+                    if let Some((key, value)) = line.split_once('=') {
+                        values.insert(key.trim().to_string(), value.trim().to_string());
+                    }
+                }
+            }
+        }
+
+        for pair in args.windows(2) {
+            if let Some(key) = pair[0].strip_prefix("--") {
+                values.insert(key.to_string(), pair[1].clone());
+            }
+        }
+
+        Self {
+            seed: field(&values, "seed", "Enter a seed for reproducible simulations:"),
+            goal: field(&values, "goal", "Enter your savings goal (in euros):"),
+            current_age: field(&values, "current-age", "Enter your current age:"),
+            target_age: field(&values, "target-age", "Enter your target age:"),
+            monthly_salary: field(
+                &values,
+                "monthly-salary",
+                "Enter your monthly net salary (in euros):",
+            ),
+            start_inflation_rate: field(
+                &values,
+                "start-inflation-rate",
+                "Enter the starting inflation rate (e.g. 0.02):",
+            ),
+            inflation_volatility: field(
+                &values,
+                "inflation-volatility",
+                "Enter the inflation volatility (e.g. 0.005):",
+            ),
+            mean_reversion_speed: field(
+                &values,
+                "mean-reversion-speed",
+                "Enter the mean reversion speed (e.g. 0.3):",
+            ),
+            long_term_inflation_mean: field(
+                &values,
+                "long-term-inflation-mean",
+                "Enter the long-term inflation mean (e.g. 0.02):",
+            ),
+            num_simulations: positive_usize(
+                &values,
+                "num-simulations",
+                "Enter the number of Monte Carlo simulations to run:",
+            ),
+            annual_return: field(
+                &values,
+                "annual-return",
+                "Enter the assumed annual investment return (e.g. 0.05):",
+            ),
+            mean_block_len: field(
+                &values,
+                "mean-block-len",
+                "Enter the mean block length L for the stationary bootstrap (e.g. 5):",
+            ),
+            history_path: field(
+                &values,
+                "history-path",
+                "Enter path to a historical inflation CSV (date,rate), or leave blank:",
+            ),
+            use_bootstrap: field(
+                &values,
+                "use-bootstrap",
+                "Use stationary block-bootstrap mode instead of the parametric OU model? (y/n):",
+            ),
+            use_pid: field(
+                &values,
+                "use-pid",
+                "Use a PID-controlled inflation process instead of mean-reversion/bootstrap? (y/n):",
+            ),
+            pid_p: field(&values, "pid-p", "Enter the PID proportional gain P:"),
+            pid_i: field(&values, "pid-i", "Enter the PID integral gain I:"),
+            pid_d: field(&values, "pid-d", "Enter the PID derivative gain D:"),
+            pid_target: field(&values, "pid-target", "Enter the PID target inflation rate:"),
+            pid_cap: field(&values, "pid-cap", "Enter the inflation cap:"),
+            run_fire: field(
+                &values,
+                "run-fire",
+                "Also run a FIRE-style projection that derives a target from your retirement expenses? (y/n):",
+            ),
+            fire_monthly_expenses: field(
+                &values,
+                "fire-monthly-expenses",
+                "Enter your current monthly expenses (in euros):",
+            ),
+            fire_income_growth_rate: field(
+                &values,
+                "fire-income-growth-rate",
+                "Enter your expected annual net salary growth rate (e.g. 0.02):",
+            ),
+            fire_retirement_monthly_expenses: field(
+                &values,
+                "fire-retirement-monthly-expenses",
+                "Enter your projected monthly expenses in retirement (in euros):",
+            ),
+            fire_withdrawal_rate: field(
+                &values,
+                "fire-withdrawal-rate",
+                "Enter the withdrawal rate to size your FI number (e.g. 0.04 for 4%):",
+            ),
+            run_decumulation: field(
+                &values,
+                "run-decumulation",
+                "Run retirement decumulation analysis? (y/n):",
+            ),
+            decumulation_ending_portfolio: field(
+                &values,
+                "decumulation-ending-portfolio",
+                "Enter the ending portfolio value (in euros):",
+            ),
+            decumulation_withdrawal_rate: field(
+                &values,
+                "decumulation-withdrawal-rate",
+                "Enter the annual withdrawal rate (e.g. 0.04 for 4%):",
+            ),
+            decumulation_life_expectancy_years: field(
+                &values,
+                "decumulation-life-expectancy-years",
+                "Enter the number of years the portfolio must last:",
+            ),
+            decumulation_annual_fee: field(
+                &values,
+                "decumulation-annual-fee",
+                "Enter the annual brokerage fee rate (e.g. 0.005):",
+            ),
+            decumulation_tax_rate: field(
+                &values,
+                "decumulation-tax-rate",
+                "Enter the tax rate on positive gains (e.g. 0.3):",
+            ),
+            decumulation_real_return: field(
+                &values,
+                "decumulation-real-return",
+                "Enter the assumed real annual return during retirement:",
+            ),
+            decumulation_target_failure_rate: field(
+                &values,
+                "decumulation-target-failure-rate",
+                "Enter the target failure rate to stay under (e.g. 0.05 for 5%):",
+            ),
+            output_format: field(
+                &values,
+                "output-format",
+                "Choose chart output: (1) PNG, (2) SVG, (3) terminal:",
+            ),
+        }
+    }
+}
+
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.windows(2)
+        .find(|pair| pair[0] == flag)
+        .map(|pair| pair[1].clone())
+}
+
+fn field<T: std::str::FromStr>(values: &HashMap<String, String>, key: &str, prompt: &str) -> T {
+    if let Some(raw) = values.get(key) {
+        if let Ok(value) = raw.parse() {
+            return value;
+        }
+    }
+    get_user_input(prompt)
+}
+
+/// Like `field`, but rejects zero: a simulation count of zero produces an
+/// empty Monte Carlo ensemble that the downstream stats can't summarize.
+fn positive_usize(values: &HashMap<String, String>, key: &str, prompt: &str) -> usize {
+    if let Some(raw) = values.get(key) {
+        if let Ok(value) = raw.parse::<usize>() {
+            if value >= 1 {
+                return value;
+            }
+        }
+    }
+    loop {
+        let value: usize = get_user_input(prompt);
+        if value >= 1 {
+            return value;
+        }
+        println!("Please enter at least 1 simulation.");
+    }
+}
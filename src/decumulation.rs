@@ -0,0 +1,109 @@
+/// Simulates one decumulation path: withdrawals grow with the path's own
+/// inflation each year, fees are charged on the balance, and tax is paid
+/// only on positive gains. Returns `false` ("ruin") if the balance hits
+/// zero before the window ends.
+fn survives(
+    starting_portfolio: f64,
+    withdrawal_rate: f64,
+    inflation_path: &[f64],
+    real_return: f64,
+    annual_fee: f64,
+    tax_rate: f64,
+) -> bool {
+    let mut balance = starting_portfolio;
+    let mut withdrawal = starting_portfolio * withdrawal_rate;
+
+    for &inflation in inflation_path {
+        balance -= withdrawal;
+        if balance <= 0.0 {
+            return false;
+        }
+
+        let gain = balance * (real_return - annual_fee);
+        let tax = gain.max(0.0) * tax_rate;
+        balance += gain - tax;
+        if balance <= 0.0 {
+            return false;
+        }
+
+        withdrawal *= 1.0 + inflation;
+    }
+
+    true
+}
+
+/// The number of years a decumulation analysis actually covers: the
+/// requested `life_expectancy_years`, capped by how long the simulated
+/// inflation paths are (the accumulation-phase paths are `years_to_simulate`
+/// long, which is independent of `life_expectancy_years`).
+pub fn effective_window_years(inflation_paths: &[Vec<f64>], life_expectancy_years: usize) -> usize {
+    inflation_paths
+        .first()
+        .map_or(0, |path| life_expectancy_years.min(path.len()))
+}
+
+/// Runs the decumulation phase across every simulated inflation path and
+/// returns the fraction of paths that end in ruin within
+/// `effective_window_years(inflation_paths, life_expectancy_years)`.
+pub fn probability_of_ruin(
+    inflation_paths: &[Vec<f64>],
+    starting_portfolio: f64,
+    withdrawal_rate: f64,
+    life_expectancy_years: usize,
+    real_return: f64,
+    annual_fee: f64,
+    tax_rate: f64,
+) -> f64 {
+    let failures = inflation_paths
+        .iter()
+        .filter(|path| {
+            let window = &path[..life_expectancy_years.min(path.len())];
+            !survives(
+                starting_portfolio,
+                withdrawal_rate,
+                window,
+                real_return,
+                annual_fee,
+                tax_rate,
+            )
+        })
+        .count();
+
+    failures as f64 / inflation_paths.len() as f64
+}
+
+/// Binary-searches the largest withdrawal rate whose probability of ruin
+/// stays below `target_failure_rate` (e.g. 0.05 for a 5% failure target).
+pub fn max_sustainable_withdrawal_rate(
+    inflation_paths: &[Vec<f64>],
+    starting_portfolio: f64,
+    life_expectancy_years: usize,
+    real_return: f64,
+    annual_fee: f64,
+    tax_rate: f64,
+    target_failure_rate: f64,
+) -> f64 {
+    let mut low = 0.0;
+    let mut high = 1.0;
+
+    for _ in 0..40 {
+        let mid = (low + high) / 2.0;
+        let ruin = probability_of_ruin(
+            inflation_paths,
+            starting_portfolio,
+            mid,
+            life_expectancy_years,
+            real_return,
+            annual_fee,
+            tax_rate,
+        );
+
+        if ruin <= target_failure_rate {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
@@ -0,0 +1,44 @@
+/// Result of projecting a FIRE-style (Financial Independence, Retire Early)
+/// trajectory: how long it takes net worth to cover retirement expenses at
+/// the given withdrawal rate, and the net worth path itself for plotting.
+pub struct FireProjection {
+    pub months_to_fi: usize,
+    pub years_to_fi: f64,
+    pub net_worth_over_time: Vec<f64>,
+}
+
+/// Projects net worth month by month until it covers `retirement_monthly_expenses`
+/// under the `withdrawal_rate` rule (the classic 4%-rule target is
+/// `annual expenses / withdrawal_rate`). Income grows by `income_growth_rate`
+/// (annual, compounded monthly) while `monthly_expenses` stays level; the
+/// difference is invested at `real_return` (annual, compounded monthly).
+pub fn project_financial_independence(
+    monthly_salary: f64,
+    income_growth_rate: f64,
+    monthly_expenses: f64,
+    real_return: f64,
+    retirement_monthly_expenses: f64,
+    withdrawal_rate: f64,
+    max_months: usize,
+) -> FireProjection {
+    let monthly_rate = (1.0 + real_return).powf(1.0 / 12.0) - 1.0;
+    let target_net_worth = retirement_monthly_expenses * 12.0 / withdrawal_rate;
+
+    let mut fv = 0.0;
+    let mut scaled_income = monthly_salary;
+    let mut net_worth_over_time = Vec::new();
+    let mut months = 0;
+
+    while fv < target_net_worth && months < max_months {
+        fv = fv * (1.0 + monthly_rate) + (scaled_income - monthly_expenses);
+        scaled_income *= 1.0 + income_growth_rate / 12.0;
+        months += 1;
+        net_worth_over_time.push(fv);
+    }
+
+    FireProjection {
+        months_to_fi: months,
+        years_to_fi: months as f64 / 12.0,
+        net_worth_over_time,
+    }
+}
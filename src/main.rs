@@ -1,62 +1,68 @@
 use std::io;
 
 use plotters::prelude::*;
+use rand::Rng;
 use rand_distr::{Distribution, Normal};
 
-fn simulate_inflation(
-    years: usize,
+mod bootstrap;
+mod calibration;
+mod config;
+mod decumulation;
+mod fire;
+mod pcg;
+mod pid;
+mod plotting;
+mod stats;
+mod terminal_chart;
+
+use bootstrap::run_bootstrap_simulations;
+use calibration::{calibrate_from_csv, read_rates_csv};
+use config::Config;
+use decumulation::{effective_window_years, max_sustainable_withdrawal_rate, probability_of_ruin};
+use fire::project_financial_independence;
+use pcg::Pcg32;
+use pid::{run_pid_simulations, PidParams};
+use stats::SimulationSummary;
+use terminal_chart::render_terminal_chart;
+
+/// Parameters of the Ornstein-Uhlenbeck inflation process driving a single
+/// simulation run, bundled together the same way `PidParams` bundles the PID
+/// gains, so callers don't have to thread four loose floats around.
+struct OuParams {
     start_rate: f64,
     volatility: f64,
     mean_reversion: f64,
     long_term_mean: f64,
-) -> Vec<f64> {
-    let mut rates = vec![start_rate];
-    let normal = Normal::new(0.0, volatility).unwrap();
-    let mut rng = rand::thread_rng();
+}
+
+fn simulate_inflation(years: usize, params: &OuParams, rng: &mut impl Rng) -> Vec<f64> {
+    let mut rates = vec![params.start_rate];
+    let normal = Normal::new(0.0, params.volatility).unwrap();
 
     for _ in 1..years {
-        let drift = mean_reversion * (long_term_mean - rates.last().unwrap());
-        let random_shock = normal.sample(&mut rng);
+        let drift = params.mean_reversion * (params.long_term_mean - rates.last().unwrap());
+        let random_shock = normal.sample(rng);
         let new_rate = (rates.last().unwrap() + drift + random_shock).max(0.0);
         rates.push(new_rate);
     }
     rates
 }
 
+/// Runs `num_simulations` independent OU paths and summarizes them across
+/// the requested `percentiles` (e.g. `[5.0, 25.0, 50.0, 75.0, 95.0]`) plus a
+/// per-year boxplot, for rendering a fan chart.
 fn run_multiple_simulations(
     num_simulations: usize,
     years: usize,
-    start_rate: f64,
-    volatility: f64,
-    mean_reversion: f64,
-    long_term_mean: f64,
-) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
-    let mut all_simulations = vec![vec![0.0; years]; num_simulations];
-
-    for sim in all_simulations.iter_mut() {
-        *sim = simulate_inflation(
-            years,
-            start_rate,
-            volatility,
-            mean_reversion,
-            long_term_mean,
-        );
-    }
-
-    let mut mean_rates = vec![0.0; years];
-    let mut lower_bound = vec![0.0; years];
-    let mut upper_bound = vec![0.0; years];
-
-    for year in 0..years {
-        let mut year_rates: Vec<f64> = all_simulations.iter().map(|sim| sim[year]).collect();
-        year_rates.sort_by(|a, b| a.partial_cmp(b).unwrap());
-
-        mean_rates[year] = year_rates.iter().sum::<f64>() / num_simulations as f64;
-        lower_bound[year] = year_rates[num_simulations / 10];
-        upper_bound[year] = year_rates[num_simulations * 9 / 10];
-    }
+    params: &OuParams,
+    percentiles: &[f64],
+    rng: &mut impl Rng,
+) -> SimulationSummary {
+    let all_simulations: Vec<Vec<f64>> = (0..num_simulations)
+        .map(|_| simulate_inflation(years, params, rng))
+        .collect();
 
-    (mean_rates, lower_bound, upper_bound)
+    stats::summarize(all_simulations, years, percentiles)
 }
 
 fn calculate_monthly_savings(
@@ -76,7 +82,7 @@ fn calculate_monthly_savings(
     (future_goal * monthly_return) / ((1.0 + monthly_return).powi(months as i32) - 1.0)
 }
 
-fn get_user_input<T: std::str::FromStr>(prompt: &str) -> T {
+pub(crate) fn get_user_input<T: std::str::FromStr>(prompt: &str) -> T {
     loop {
         println!("{}", prompt);
         let mut input = String::new();
@@ -91,34 +97,128 @@ fn get_user_input<T: std::str::FromStr>(prompt: &str) -> T {
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let goal: f64 = get_user_input("Enter your savings goal (in euros):");
-    let current_age: u32 = get_user_input("Enter your current age:");
-    let target_age: u32 = get_user_input("Enter your target age:");
-    let monthly_salary: f64 = get_user_input("Enter your monthly net salary (in euros):");
+    let config = Config::load();
+    let mut rng = Pcg32::new(config.seed);
+
+    let goal = config.goal;
+    let current_age = config.current_age;
+    let target_age = config.target_age;
+    let monthly_salary = config.monthly_salary;
 
     let years_to_simulate = (target_age - current_age) as usize;
-    let start_inflation_rate = 0.02;
-    let inflation_volatility = 0.005;
-    let mean_reversion_speed = 0.3;
-    let long_term_inflation_mean = 0.02;
-    let num_simulations = 1000;
-    let annual_return = 0.05; // Assuming a 5% annual return on investments
-
-    let (mean_rates, lower_bound, upper_bound) = run_multiple_simulations(
-        num_simulations,
-        years_to_simulate,
-        start_inflation_rate,
-        inflation_volatility,
-        mean_reversion_speed,
-        long_term_inflation_mean,
-    );
+    let num_simulations = config.num_simulations;
+    let annual_return = config.annual_return;
+
+    let history_path = config.history_path.clone();
+    let default_ou_params = OuParams {
+        start_rate: config.start_inflation_rate,
+        volatility: config.inflation_volatility,
+        mean_reversion: config.mean_reversion_speed,
+        long_term_mean: config.long_term_inflation_mean,
+    };
+    let ou_params = if history_path.trim().is_empty() {
+        default_ou_params
+    } else {
+        match calibrate_from_csv(history_path.trim()) {
+            Ok(params) => {
+                println!(
+                    "Calibrated from history: start_rate={:.4}, volatility={:.4}, mean_reversion={:.4}, long_term_mean={:.4}",
+                    params.start_rate,
+                    params.volatility,
+                    params.mean_reversion,
+                    params.long_term_mean
+                );
+                OuParams {
+                    start_rate: params.start_rate,
+                    volatility: params.volatility,
+                    mean_reversion: params.mean_reversion,
+                    long_term_mean: params.long_term_mean,
+                }
+            }
+            Err(err) => {
+                println!("Failed to calibrate from {}: {}. Using defaults.", history_path, err);
+                default_ou_params
+            }
+        }
+    };
+
+    let use_bootstrap: String = if history_path.trim().is_empty() {
+        String::new()
+    } else {
+        config.use_bootstrap.clone()
+    };
+
+    let use_pid = config.use_pid.clone();
+
+    let fan_percentiles = [5.0, 25.0, 50.0, 75.0, 95.0];
+
+    let summary = if use_pid.trim().eq_ignore_ascii_case("y") {
+        let pid_params = PidParams {
+            p: config.pid_p,
+            i: config.pid_i,
+            d: config.pid_d,
+            target: config.pid_target,
+            cap: config.pid_cap,
+        };
+
+        run_pid_simulations(
+            num_simulations,
+            years_to_simulate,
+            ou_params.start_rate,
+            ou_params.volatility,
+            &pid_params,
+            &fan_percentiles,
+            &mut rng,
+        )
+    } else if use_bootstrap.trim().eq_ignore_ascii_case("y") {
+        let bootstrap_result = match read_rates_csv(history_path.trim()) {
+            Ok(history) => run_bootstrap_simulations(
+                num_simulations,
+                years_to_simulate,
+                &history,
+                config.mean_block_len,
+                &fan_percentiles,
+                &mut rng,
+            ),
+            Err(err) => Err(err),
+        };
+
+        match bootstrap_result {
+            Ok(summary) => summary,
+            Err(err) => {
+                println!(
+                    "Failed to run the block-bootstrap for {}: {}. Falling back to the parametric model.",
+                    history_path, err
+                );
+                run_multiple_simulations(
+                    num_simulations,
+                    years_to_simulate,
+                    &ou_params,
+                    &fan_percentiles,
+                    &mut rng,
+                )
+            }
+        }
+    } else {
+        run_multiple_simulations(
+            num_simulations,
+            years_to_simulate,
+            &ou_params,
+            &fan_percentiles,
+            &mut rng,
+        )
+    };
+
+    let mean_rates = &summary.mean;
+    let lower_bound = &summary.percentiles.first().unwrap().1;
+    let upper_bound = &summary.percentiles.last().unwrap().1;
 
     let mean_savings =
-        calculate_monthly_savings(goal, years_to_simulate, &mean_rates, annual_return);
+        calculate_monthly_savings(goal, years_to_simulate, mean_rates, annual_return);
     let lower_savings =
-        calculate_monthly_savings(goal, years_to_simulate, &lower_bound, annual_return);
+        calculate_monthly_savings(goal, years_to_simulate, lower_bound, annual_return);
     let upper_savings =
-        calculate_monthly_savings(goal, years_to_simulate, &upper_bound, annual_return);
+        calculate_monthly_savings(goal, years_to_simulate, upper_bound, annual_return);
 
     println!(
         "\nTo reach your goal of €{:.2} by age {}:",
@@ -140,53 +240,154 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         savings_ratio * 100.0
     );
 
-    // Plotting
-    let root =
-        BitMapBackend::new("france_inflation_simulation.png", (800, 600)).into_drawing_area();
-    root.fill(&WHITE)?;
+    let run_fire = config.run_fire.clone();
+    if run_fire.trim().eq_ignore_ascii_case("y") {
+        let monthly_expenses = config.fire_monthly_expenses;
+        let income_growth_rate = config.fire_income_growth_rate;
+        let retirement_monthly_expenses = config.fire_retirement_monthly_expenses;
+        let withdrawal_rate = config.fire_withdrawal_rate;
 
-    let mut chart = ChartBuilder::on(&root)
-        .caption(
-            "Simulated Inflation Rates in France",
-            ("sans-serif", 30).into_font(),
-        )
-        .margin(5)
-        .x_label_area_size(30)
-        .y_label_area_size(30)
-        .build_cartesian_2d(0.0..10.0, 0.0..0.04)?;
-
-    chart.configure_mesh().draw()?;
-
-    chart
-        .draw_series(LineSeries::new(
-            (0..years_to_simulate).map(|x| (x as f64, mean_rates[x])),
-            &RED,
-        ))?
-        .label("Mean Inflation Rate")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
-
-    chart
-        .draw_series(AreaSeries::new(
-            (0..years_to_simulate).map(|x| (x as f64, lower_bound[x])),
-            0.0,
-            &BLUE.mix(0.2),
-        ))?
-        .label("80% Confidence Interval")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE.mix(0.2)));
-
-    chart.draw_series(AreaSeries::new(
-        (0..years_to_simulate).map(|x| (x as f64, upper_bound[x])),
-        0.0,
-        &BLUE.mix(0.2),
-    ))?;
-
-    chart
-        .configure_series_labels()
-        .background_style(&WHITE.mix(0.8))
-        .border_style(&BLACK)
-        .draw()?;
-
-    root.present()?;
+        let max_months = 100 * 12;
+        let projection = project_financial_independence(
+            monthly_salary,
+            income_growth_rate,
+            monthly_expenses,
+            annual_return,
+            retirement_monthly_expenses,
+            withdrawal_rate,
+            max_months,
+        );
+
+        if projection.months_to_fi >= max_months {
+            println!("\nAt this savings rate, financial independence is not reached within 100 years.");
+        } else {
+            println!(
+                "\nFinancial independence reached in {} months (~{:.1} years).",
+                projection.months_to_fi, projection.years_to_fi
+            );
+        }
+
+        let net_worth_root =
+            BitMapBackend::new("net_worth_projection.png", (800, 600)).into_drawing_area();
+        net_worth_root.fill(&WHITE)?;
+
+        let max_net_worth = projection
+            .net_worth_over_time
+            .iter()
+            .cloned()
+            .fold(0.0, f64::max);
+
+        let mut net_worth_chart = ChartBuilder::on(&net_worth_root)
+            .caption("Net Worth Projection to FIRE", ("sans-serif", 30).into_font())
+            .margin(5)
+            .x_label_area_size(30)
+            .y_label_area_size(60)
+            .build_cartesian_2d(
+                0.0..projection.net_worth_over_time.len() as f64,
+                0.0..(max_net_worth * 1.1).max(1.0),
+            )?;
+
+        net_worth_chart.configure_mesh().draw()?;
+
+        net_worth_chart
+            .draw_series(LineSeries::new(
+                projection
+                    .net_worth_over_time
+                    .iter()
+                    .enumerate()
+                    .map(|(month, &net_worth)| (month as f64, net_worth)),
+                &RED,
+            ))?
+            .label("Net Worth")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+        net_worth_chart
+            .configure_series_labels()
+            .background_style(&WHITE.mix(0.8))
+            .border_style(&BLACK)
+            .draw()?;
+
+        net_worth_root.present()?;
+    }
+
+    let run_decumulation = config.run_decumulation.clone();
+    if run_decumulation.trim().eq_ignore_ascii_case("y") {
+        let ending_portfolio = config.decumulation_ending_portfolio;
+        let withdrawal_rate = config.decumulation_withdrawal_rate;
+        let life_expectancy_years = config.decumulation_life_expectancy_years;
+        let annual_fee = config.decumulation_annual_fee;
+        let tax_rate = config.decumulation_tax_rate;
+        let decumulation_real_return = config.decumulation_real_return;
+        let target_failure_rate = config.decumulation_target_failure_rate;
+
+        let ruin_probability = probability_of_ruin(
+            &summary.paths,
+            ending_portfolio,
+            withdrawal_rate,
+            life_expectancy_years,
+            decumulation_real_return,
+            annual_fee,
+            tax_rate,
+        );
+        let max_withdrawal_rate = max_sustainable_withdrawal_rate(
+            &summary.paths,
+            ending_portfolio,
+            life_expectancy_years,
+            decumulation_real_return,
+            annual_fee,
+            tax_rate,
+            target_failure_rate,
+        );
+
+        let analyzed_years = effective_window_years(&summary.paths, life_expectancy_years);
+        if analyzed_years < life_expectancy_years {
+            println!(
+                "\nNote: only {} years of simulated inflation are available; the analysis below covers {} years, not the requested {}.",
+                analyzed_years, analyzed_years, life_expectancy_years
+            );
+        }
+        println!(
+            "\nAt a {:.1}% withdrawal rate, probability of ruin within {} years: {:.1}%",
+            withdrawal_rate * 100.0,
+            analyzed_years,
+            ruin_probability * 100.0
+        );
+        println!(
+            "Maximum inflation-adjusted withdrawal rate keeping failure below {:.1}%: {:.2}%",
+            target_failure_rate * 100.0,
+            max_withdrawal_rate * 100.0
+        );
+    }
+
+    // Plotting: a fan chart of nested percentile bands around the median,
+    // with a per-year boxplot overlay showing the full Monte Carlo spread.
+    // Rendered to whichever backend the user picked, since headless/CI runs
+    // have no image viewer to open a PNG or SVG with.
+    let output_format = config.output_format.clone();
+    match output_format.trim() {
+        "2" => {
+            let root = SVGBackend::new("france_inflation_simulation.svg", (800, 600))
+                .into_drawing_area();
+            plotting::draw_fan_chart(&root, years_to_simulate, mean_rates, &summary)?;
+        }
+        "3" => {
+            let lower_percentile = summary.percentiles.first().unwrap().0;
+            let upper_percentile = summary.percentiles.last().unwrap().0;
+            render_terminal_chart(
+                years_to_simulate,
+                mean_rates,
+                lower_bound,
+                lower_percentile,
+                upper_bound,
+                upper_percentile,
+            );
+        }
+        _ => {
+            let root = BitMapBackend::new("france_inflation_simulation.png", (800, 600))
+                .into_drawing_area();
+            plotting::draw_fan_chart(&root, years_to_simulate, mean_rates, &summary)?;
+        }
+    }
 
     println!(
         "Estimated inflation rates for the next {} years:",
@@ -0,0 +1,69 @@
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+
+use crate::stats::{self, SimulationSummary};
+
+/// Gains and setpoint for the PID-controlled inflation process, an
+/// alternative to plain mean-reversion that can overshoot and oscillate
+/// around a target the way a real policy-driven process might.
+pub struct PidParams {
+    pub p: f64,
+    pub i: f64,
+    pub d: f64,
+    pub target: f64,
+    pub cap: f64,
+}
+
+/// Drives the inflation rate with a discrete PID controller tracking
+/// `pid.target`: each step computes the error, accumulates it for the
+/// integral term, differences it against the previous step for the
+/// derivative term, and adds a Gaussian shock, clamping the result to
+/// `[0, pid.cap]`.
+fn simulate_inflation_pid(
+    years: usize,
+    start_rate: f64,
+    volatility: f64,
+    pid: &PidParams,
+    rng: &mut impl Rng,
+) -> Vec<f64> {
+    let mut rates = vec![start_rate];
+    let normal = Normal::new(0.0, volatility).unwrap();
+    let mut integral_error = 0.0;
+    let mut prev_error = pid.target - start_rate;
+    let cap = pid.cap.max(0.0);
+
+    for _ in 1..years {
+        let current = *rates.last().unwrap();
+        let error = pid.target - current;
+        integral_error += error;
+        let derivative = error - prev_error;
+
+        let control = pid.p * error + pid.i * integral_error + pid.d * derivative;
+        let shock = normal.sample(rng);
+        let new_rate = (current + control + shock).clamp(0.0, cap);
+
+        rates.push(new_rate);
+        prev_error = error;
+    }
+
+    rates
+}
+
+/// PID-controlled counterpart to `run_multiple_simulations`, summarized the
+/// same way (mean, percentiles, boxplot) so it can be compared on the same
+/// fan chart as the parametric and bootstrap modes.
+pub fn run_pid_simulations(
+    num_simulations: usize,
+    years: usize,
+    start_rate: f64,
+    volatility: f64,
+    pid: &PidParams,
+    percentiles: &[f64],
+    rng: &mut impl Rng,
+) -> SimulationSummary {
+    let all_simulations: Vec<Vec<f64>> = (0..num_simulations)
+        .map(|_| simulate_inflation_pid(years, start_rate, volatility, pid, rng))
+        .collect();
+
+    stats::summarize(all_simulations, years, percentiles)
+}
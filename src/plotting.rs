@@ -0,0 +1,93 @@
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+use crate::stats::SimulationSummary;
+
+/// Draws the fan chart (nested percentile bands around the median, mean
+/// line, and per-year boxplot overlay) onto any plotters drawing backend,
+/// so the same chart can be rendered to a bitmap, an SVG, or anywhere else
+/// plotters supports.
+pub fn draw_fan_chart<DB>(
+    root: &DrawingArea<DB, Shift>,
+    years_to_simulate: usize,
+    mean_rates: &[f64],
+    summary: &SimulationSummary,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(root)
+        .caption(
+            "Simulated Inflation Rates in France",
+            ("sans-serif", 30).into_font(),
+        )
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(0.0..years_to_simulate as f64, 0.0..0.06)?;
+
+    chart.configure_mesh().draw()?;
+
+    // Percentiles are sorted ascending with the median in the middle; pair
+    // symmetric bands (e.g. 5th/95th, then 25th/75th) and shade them with
+    // increasing opacity the closer they sit to the median.
+    let band_count = summary.percentiles.len() / 2;
+    for band in 0..band_count {
+        let (low_p, low_series) = &summary.percentiles[band];
+        let (high_p, high_series) = &summary.percentiles[summary.percentiles.len() - 1 - band];
+        let opacity = 0.15 + 0.15 * band as f64;
+
+        let band_points: Vec<(f64, f64)> = (0..years_to_simulate)
+            .map(|x| (x as f64, high_series[x]))
+            .chain((0..years_to_simulate).rev().map(|x| (x as f64, low_series[x])))
+            .collect();
+
+        chart
+            .draw_series(std::iter::once(Polygon::new(
+                band_points,
+                BLUE.mix(opacity).filled(),
+            )))?
+            .label(format!("{:.0}th-{:.0}th percentile", low_p, high_p))
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], BLUE.mix(opacity))
+            });
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            (0..years_to_simulate).map(|x| (x as f64, mean_rates[x])),
+            &RED,
+        ))?
+        .label("Mean Inflation Rate")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+
+    let box_half_width = 0.15;
+    for (year, bp) in summary.boxplots.iter().enumerate() {
+        let x = year as f64;
+        chart.draw_series(std::iter::once(PathElement::new(
+            vec![(x, bp.min), (x, bp.max)],
+            BLACK.mix(0.6),
+        )))?;
+        chart.draw_series(std::iter::once(Rectangle::new(
+            [(x - box_half_width, bp.q1), (x + box_half_width, bp.q3)],
+            BLACK.mix(0.4).filled(),
+        )))?;
+        chart.draw_series(std::iter::once(PathElement::new(
+            vec![(x - box_half_width, bp.median), (x + box_half_width, bp.median)],
+            BLACK,
+        )))?;
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(&WHITE.mix(0.8))
+        .border_style(&BLACK)
+        .draw()?;
+
+    root.present()?;
+
+    Ok(())
+}
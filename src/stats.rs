@@ -0,0 +1,136 @@
+/// Five-number summary of a distribution at a single point in time, used to
+/// render per-year boxplot overlays.
+pub struct BoxplotStats {
+    pub min: f64,
+    pub q1: f64,
+    pub median: f64,
+    pub q3: f64,
+    pub max: f64,
+}
+
+/// Linear-interpolated percentile (`p` in `[0, 100]`) of an already-sorted
+/// slice.
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let n = sorted.len();
+    if n == 0 {
+        return 0.0;
+    }
+    if n == 1 {
+        return sorted[0];
+    }
+
+    let rank = (p / 100.0) * (n - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] * (1.0 - frac) + sorted[upper] * frac
+    }
+}
+
+/// Computes the requested percentiles of `simulations` for each year,
+/// returning one series per percentile in the same order as `percentiles`.
+pub fn percentiles_by_year(
+    simulations: &[Vec<f64>],
+    years: usize,
+    percentiles: &[f64],
+) -> Vec<Vec<f64>> {
+    let mut series = vec![vec![0.0; years]; percentiles.len()];
+
+    for year in 0..years {
+        let mut year_values: Vec<f64> = simulations.iter().map(|sim| sim[year]).collect();
+        year_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for (i, &p) in percentiles.iter().enumerate() {
+            series[i][year] = percentile(&year_values, p);
+        }
+    }
+
+    series
+}
+
+/// Computes a min/Q1/median/Q3/max boxplot summary of `simulations` for
+/// each year.
+pub fn boxplot_stats_by_year(simulations: &[Vec<f64>], years: usize) -> Vec<BoxplotStats> {
+    (0..years)
+        .map(|year| {
+            let mut year_values: Vec<f64> = simulations.iter().map(|sim| sim[year]).collect();
+            year_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            BoxplotStats {
+                min: *year_values.first().unwrap(),
+                q1: percentile(&year_values, 25.0),
+                median: percentile(&year_values, 50.0),
+                q3: percentile(&year_values, 75.0),
+                max: *year_values.last().unwrap(),
+            }
+        })
+        .collect()
+}
+
+fn mean_by_year(simulations: &[Vec<f64>], years: usize) -> Vec<f64> {
+    let num_simulations = simulations.len() as f64;
+    (0..years)
+        .map(|year| simulations.iter().map(|sim| sim[year]).sum::<f64>() / num_simulations)
+        .collect()
+}
+
+/// Full summary of a Monte Carlo ensemble of inflation paths: the raw paths
+/// themselves (for downstream consumers like decumulation analysis), the
+/// per-year mean, an arbitrary set of per-year percentiles for fan charts,
+/// and per-year boxplot stats.
+pub struct SimulationSummary {
+    pub paths: Vec<Vec<f64>>,
+    pub mean: Vec<f64>,
+    pub percentiles: Vec<(f64, Vec<f64>)>,
+    pub boxplots: Vec<BoxplotStats>,
+}
+
+/// Builds a `SimulationSummary` out of a raw ensemble of simulated paths.
+pub fn summarize(paths: Vec<Vec<f64>>, years: usize, percentiles: &[f64]) -> SimulationSummary {
+    let mean = mean_by_year(&paths, years);
+    let percentile_series = percentiles_by_year(&paths, years, percentiles);
+    let boxplots = boxplot_stats_by_year(&paths, years);
+
+    SimulationSummary {
+        paths,
+        mean,
+        percentiles: percentiles.iter().copied().zip(percentile_series).collect(),
+        boxplots,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_of_empty_slice_is_zero() {
+        assert_eq!(percentile(&[], 50.0), 0.0);
+    }
+
+    #[test]
+    fn percentile_of_single_value_is_that_value() {
+        assert_eq!(percentile(&[0.03], 50.0), 0.03);
+    }
+
+    #[test]
+    fn percentile_interpolates_known_values() {
+        let sorted = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(percentile(&sorted, 0.0), 1.0);
+        assert_eq!(percentile(&sorted, 100.0), 4.0);
+        assert_eq!(percentile(&sorted, 50.0), 2.5);
+    }
+
+    #[test]
+    fn boxplot_stats_match_known_five_number_summary() {
+        let simulations = vec![vec![1.0], vec![2.0], vec![3.0], vec![4.0]];
+        let boxplots = boxplot_stats_by_year(&simulations, 1);
+        let b = &boxplots[0];
+        assert_eq!(b.min, 1.0);
+        assert_eq!(b.median, 2.5);
+        assert_eq!(b.max, 4.0);
+    }
+}
@@ -0,0 +1,57 @@
+/// Renders the mean inflation line and its confidence band directly to the
+/// terminal as a block/braille-style ASCII chart, for headless machines or
+/// CI where no image viewer is available.
+pub fn render_terminal_chart(
+    years_to_simulate: usize,
+    mean_rates: &[f64],
+    lower_bound: &[f64],
+    lower_percentile: f64,
+    upper_bound: &[f64],
+    upper_percentile: f64,
+) {
+    const HEIGHT: usize = 20;
+
+    let max_value = upper_bound
+        .iter()
+        .chain(mean_rates.iter())
+        .cloned()
+        .fold(0.0_f64, f64::max)
+        .max(1e-6);
+
+    println!("\nSimulated Inflation Rates in France (terminal fan chart)");
+    for row in (0..HEIGHT).rev() {
+        let y_value = max_value * row as f64 / (HEIGHT - 1) as f64;
+        let band_half_width = max_value / HEIGHT as f64 / 2.0;
+
+        print!("{:>6.2}% │", y_value * 100.0);
+        for col in 0..years_to_simulate {
+            let in_band = y_value >= lower_bound[col] && y_value <= upper_bound[col];
+            let on_mean = (y_value - mean_rates[col]).abs() <= band_half_width;
+
+            let marker = if on_mean {
+                '⣿'
+            } else if in_band {
+                '▒'
+            } else {
+                ' '
+            };
+            print!("{}", marker);
+        }
+        println!();
+    }
+
+    print!("       └");
+    for _ in 0..years_to_simulate {
+        print!("─");
+    }
+    println!();
+    println!(
+        "        year 0{}year {}",
+        " ".repeat(years_to_simulate.saturating_sub(10)),
+        years_to_simulate.saturating_sub(1)
+    );
+    println!(
+        "        ⣿ mean rate   ▒ {:.0}th-{:.0}th percentile band",
+        lower_percentile, upper_percentile
+    );
+}